@@ -0,0 +1,60 @@
+use near_sdk::json_types::{ValidAccountId, U128};
+use near_sdk::near_bindgen;
+
+use crate::*;
+
+/// a single call can't be used to exhaust the view call's gas on large collections
+const MAX_ENUMERATION_LIMIT: u64 = 50;
+
+/// NEP-181 view methods
+#[near_bindgen]
+impl Contract {
+    pub fn nft_total_supply(&self) -> U128 {
+        U128(self.tokens_by_id.len() as u128)
+    }
+
+    pub fn nft_tokens(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<Token> {
+        let from_index: u128 = from_index.map(|i| i.0).unwrap_or(0);
+        let limit = limit.unwrap_or(MAX_ENUMERATION_LIMIT).min(MAX_ENUMERATION_LIMIT);
+        self.tokens_by_id
+            .values()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    pub fn nft_supply_for_owner(&self, account_id: ValidAccountId) -> U128 {
+        let account_id: AccountId = account_id.into();
+        U128(
+            self.tokens_per_owner
+                .get(&account_id)
+                .map(|tokens| tokens.len() as u128)
+                .unwrap_or(0),
+        )
+    }
+
+    pub fn nft_tokens_for_owner(
+        &self,
+        account_id: ValidAccountId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<Token> {
+        let account_id: AccountId = account_id.into();
+        let from_index: u128 = from_index.map(|i| i.0).unwrap_or(0);
+        let limit = limit.unwrap_or(MAX_ENUMERATION_LIMIT).min(MAX_ENUMERATION_LIMIT);
+        let tokens_for_owner = match self.tokens_per_owner.get(&account_id) {
+            Some(tokens) => tokens,
+            None => return vec![],
+        };
+        tokens_for_owner
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(|token_id| {
+                self.tokens_by_id
+                    .get(&token_id)
+                    .expect("token in tokens_per_owner missing from tokens_by_id")
+            })
+            .collect()
+    }
+}
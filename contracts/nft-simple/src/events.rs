@@ -0,0 +1,81 @@
+use near_sdk::serde::Serialize;
+use near_sdk::{env, AccountId};
+
+use crate::TokenId;
+
+const EVENT_JSON_PREFIX: &str = "EVENT_JSON:";
+
+/// Standard NEP-171 event data, serialized and logged with the `EVENT_JSON:` prefix
+/// so off-chain indexers can reconstruct contract state (including guest activity)
+/// without polling every view method.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftEventLog<'a> {
+    pub standard: &'a str,
+    pub version: &'a str,
+
+    #[serde(flatten)]
+    pub event: NearEvent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+// variants keep the `Nft*` prefix to match the NEP-171 event names verbatim (`nft_mint`, etc.)
+#[allow(clippy::enum_variant_names)]
+pub enum NearEvent<'a> {
+    NftMint(Vec<NftMintData<'a>>),
+    NftTransfer(Vec<NftTransferData<'a>>),
+    NftApprove(Vec<NftApproveData<'a>>),
+    NftRevoke(Vec<NftRevokeData<'a>>),
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftMintData<'a> {
+    pub owner_id: &'a AccountId,
+    pub token_ids: Vec<&'a TokenId>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftTransferData<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<&'a AccountId>,
+    pub old_owner_id: &'a AccountId,
+    pub new_owner_id: &'a AccountId,
+    pub token_ids: Vec<&'a TokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftApproveData<'a> {
+    pub token_id: &'a TokenId,
+    pub owner_id: &'a AccountId,
+    pub approved_account_id: &'a AccountId,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftRevokeData<'a> {
+    pub token_id: &'a TokenId,
+    pub owner_id: &'a AccountId,
+    pub revoked_account_id: &'a AccountId,
+}
+
+impl<'a> NearEvent<'a> {
+    /// Serializes and logs the event, prefixed with `EVENT_JSON:` per NEP-297.
+    pub fn emit(self) {
+        let log = NftEventLog {
+            standard: "nep171",
+            version: "1.0.0",
+            event: self,
+        };
+        let serialized = near_sdk::serde_json::to_string(&log)
+            .unwrap_or_else(|_| env::panic(b"failed to serialize event"));
+        env::log(format!("{}{}", EVENT_JSON_PREFIX, serialized).as_bytes());
+    }
+}
@@ -0,0 +1,66 @@
+use near_sdk::collections::UnorderedSet;
+use near_sdk::{env, Promise, StorageUsage};
+
+use crate::*;
+
+/// NEP-171's required guard on transfer/approval methods: the attached deposit must be exactly
+/// 1 yoctoNEAR, so a full-access key is needed to move or approve a token and a restricted
+/// function-call key (e.g. a guest's) can't.
+pub(crate) fn assert_one_yocto() {
+    assert_eq!(
+        env::attached_deposit(),
+        1,
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+}
+
+/// Charges the predecessor for the storage a call just used, refunding any excess of the
+/// attached deposit. Used by the paying (non-guest) mint path.
+pub(crate) fn refund_deposit(storage_used: StorageUsage) {
+    let required_cost = env::storage_byte_cost() * Balance::from(storage_used);
+    let attached_deposit = env::attached_deposit();
+
+    assert!(
+        attached_deposit >= required_cost,
+        "Must attach at least {} yoctoNEAR to cover storage",
+        required_cost
+    );
+
+    let refund = attached_deposit - required_cost;
+    if refund > 1 {
+        Promise::new(env::predecessor_account_id()).transfer(refund);
+    }
+}
+
+/// Derives a storage prefix for `account_id`'s entry in `tokens_per_owner`. Other per-account
+/// `UnorderedSet`s (e.g. `roles`'s `unique_role_prefix`) must use a different leading byte so
+/// they never collide with this one on the same trie keys.
+pub(crate) fn unique_prefix(account_id: &AccountId) -> Vec<u8> {
+    let mut prefix = Vec::with_capacity(33);
+    prefix.push(b'o');
+    prefix.extend(env::sha256(account_id.as_bytes()));
+    prefix
+}
+
+impl Contract {
+    pub(crate) fn internal_add_token_to_owner(&mut self, account_id: &AccountId, token_id: &TokenId) {
+        let mut tokens_set = self.tokens_per_owner.get(account_id).unwrap_or_else(|| {
+            UnorderedSet::new(unique_prefix(account_id))
+        });
+        tokens_set.insert(token_id);
+        self.tokens_per_owner.insert(account_id, &tokens_set);
+    }
+
+    pub(crate) fn internal_remove_token_from_owner(&mut self, account_id: &AccountId, token_id: &TokenId) {
+        let mut tokens_set = self
+            .tokens_per_owner
+            .get(account_id)
+            .expect("account does not own any tokens");
+        tokens_set.remove(token_id);
+        if tokens_set.is_empty() {
+            self.tokens_per_owner.remove(account_id);
+        } else {
+            self.tokens_per_owner.insert(account_id, &tokens_set);
+        }
+    }
+}
@@ -1,18 +1,31 @@
 use std::collections::HashSet;
 
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LookupMap, UnorderedMap, UnorderedSet};
+use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet};
 use near_sdk::json_types::{U128, ValidAccountId, Base58PublicKey};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{env, ext_contract, near_bindgen, Gas, PublicKey, AccountId, Balance, PanicOnDefault, Promise, PromiseResult, StorageUsage};
 
+use crate::events::{NearEvent, NftApproveData, NftMintData, NftRevokeData};
 use crate::internal::*;
-pub use crate::mint::*;
 pub use crate::nft_core::*;
 
+mod enumeration;
+mod events;
 mod internal;
+mod metadata;
+mod migration;
 mod mint;
 mod nft_core;
+mod pricing;
+mod rbac;
+mod upgrade;
+
+pub use crate::metadata::{NFTContractMetadata, TokenMetadata};
+pub use crate::migration::OngoingOperation;
+pub use crate::pricing::BondingCurve;
+pub use crate::rbac::Role;
+pub use crate::upgrade::{Upgrade, UpgradeHook};
 
 #[global_allocator]
 static ALLOC: near_sdk::wee_alloc::WeeAlloc<'_> = near_sdk::wee_alloc::WeeAlloc::INIT;
@@ -31,7 +44,7 @@ pub type TokenId = String;
 #[serde(crate = "near_sdk::serde")]
 pub struct Token {
     pub owner_id: AccountId,
-    pub metadata: String,
+    pub metadata: TokenMetadata,
     pub approved_account_ids: HashSet<AccountId>,
 }
 
@@ -49,6 +62,9 @@ pub struct GuestSale {
     pub public_key: PublicKey,
     pub price: Balance,
     pub deposit: Balance,
+    /// whether `price` came from `curve_price`, so `nft_remove_sale_guest` knows to give the
+    /// reserved `active_curve_listings` slot back instead of leaving the curve permanently moved
+    pub used_curve: bool,
 }
 
 #[near_bindgen]
@@ -69,18 +85,48 @@ pub struct Contract {
     /// custom fields for example
     pub guests: LookupMap<PublicKey, Guest>,
     pub guest_sales: LookupMap<TokenId, GuestSale>,
+
+    /// role-based access control, delegated from owner_id
+    pub roles: LookupMap<AccountId, UnorderedSet<Role>>,
+
+    /// halts sponsored guest activity during an incident, without redeploying
+    pub paused: bool,
+
+    /// resumable guest inventory migrations, keyed by the account being migrated from
+    pub migrations: LookupMap<AccountId, OngoingOperation>,
+
+    /// NEP-177 contract-level metadata, set once at `new`
+    pub metadata: LazyOption<NFTContractMetadata>,
+
+    /// optional owner-configured linear bonding curve for guest sale auto-pricing
+    pub bonding_curve: Option<BondingCurve>,
+    /// count of guest sales listed under the bonding curve and not since withdrawn. Reserved on
+    /// listing and released only by `nft_remove_sale_guest` (see `release_curve_slot`) so
+    /// delisting doesn't permanently move the curve; a listing that sells through the market
+    /// keeps its slot, since the market contract has no sale-completion callback into this one.
+    /// Fed into `BondingCurve::price_at` as its input.
+    pub active_curve_listings: u64,
 }
 
 #[near_bindgen]
 impl Contract {
     #[init]
-    pub fn new(owner_id: ValidAccountId) -> Self {
+    pub fn new(owner_id: ValidAccountId, metadata: NFTContractMetadata) -> Self {
         assert!(!env::state_exists(), "Already initialized");
         let mut this = Self {
             tokens_per_owner: LookupMap::new(b"a".to_vec()),
             tokens_by_id: UnorderedMap::new(b"t".to_vec()),
             guests: LookupMap::new(b"g".to_vec()),
             guest_sales: LookupMap::new(b"m".to_vec()),
+            roles: LookupMap::new(b"r".to_vec()),
+            paused: false,
+            // 'O' (not 'o') so this top-level prefix can't collide with internal::unique_prefix's
+            // b'o'-led per-account UnorderedSet prefixes, the same discipline unique_role_prefix
+            // keeps for roles
+            migrations: LookupMap::new(b"O".to_vec()),
+            metadata: LazyOption::new(b"md".to_vec(), Some(&metadata)),
+            bonding_curve: None,
+            active_curve_listings: 0,
             owner_id: owner_id.into(),
             total_supply: 0,
             extra_storage_in_bytes_per_token: 0,
@@ -104,18 +150,23 @@ impl Contract {
         self.tokens_per_owner.remove(&tmp_account_id);
     }
 
-    /// non-standard methods for guest and free mint/approval management
+    // non-standard methods for guest and free mint/approval management
 
-    /// guest mint restricts token ID and metadata size 
+    /// guest mint restricts token ID and metadata size
     /// contract needs to know upper bound of storage it will sponsor
     /// guests are limited mints and approvals
-    pub fn nft_mint_guest(&mut self, token_id: TokenId, metadata: String) {
+    pub fn nft_mint_guest(&mut self, token_id: TokenId, metadata: TokenMetadata) {
+        self.assert_not_paused();
         assert!(
             token_id.len() < GUEST_STRING_LENGTH_LIMIT,
             "Token ID too long for guest mint"
         );
+        metadata.assert_valid();
+        let serialized_size = near_sdk::serde_json::to_string(&metadata)
+            .unwrap_or_else(|_| env::panic(b"failed to serialize metadata"))
+            .len();
         assert!(
-            metadata.len() < GUEST_STRING_LENGTH_LIMIT,
+            serialized_size < GUEST_STRING_LENGTH_LIMIT,
             "Metadata too long for guest mint"
         );
         let guest = self.admin_guest(1);
@@ -131,11 +182,26 @@ impl Contract {
         );
         self.internal_add_token_to_owner(&token.owner_id, &token_id);
         self.total_supply += 1;
+
+        NearEvent::NftMint(vec![NftMintData {
+            owner_id: &token.owner_id,
+            token_ids: vec![&token_id],
+        }])
+        .emit();
     }
 
-    pub fn nft_add_sale_guest(&mut self, token_id: TokenId, price: U128, market_id: ValidAccountId, market_deposit: U128) {
+    pub fn nft_add_sale_guest(
+        &mut self,
+        token_id: TokenId,
+        price: U128,
+        market_id: ValidAccountId,
+        market_deposit: U128,
+        use_curve: bool,
+    ) {
+        self.assert_not_paused();
         let deposit: Balance = market_deposit.into();
         assert!(deposit <= MAX_MARKET_DEPOSIT, "Cannot make market deposits more than {}", MAX_MARKET_DEPOSIT);
+        let price: U128 = if use_curve { U128(self.curve_price()) } else { price };
         let guest = self.admin_guest(0);
         let mut token = self.tokens_by_id.get(&token_id).expect("Token not found");
         assert_eq!(&guest.account_id, &token.owner_id);
@@ -147,9 +213,18 @@ impl Contract {
         self.tokens_by_id.insert(&token_id, &token);
         self.guest_sales.insert(&token_id, &GuestSale {
             public_key: env::signer_account_pk(),
-            price: price.clone().into(),
-            deposit: deposit.clone()
+            price: price.into(),
+            deposit,
+            used_curve: use_curve,
         });
+
+        NearEvent::NftApprove(vec![NftApproveData {
+            token_id: &token_id,
+            owner_id: &guest.account_id,
+            approved_account_id: &market_contract,
+        }])
+        .emit();
+
         // make the market add sale
         ext_market::add_sale(
             env::current_account_id(),
@@ -172,7 +247,18 @@ impl Contract {
         // TODO should be handled in promise after market contract promise is successful
         token.approved_account_ids.remove(&market_contract.clone());
         self.tokens_by_id.insert(&token_id, &token);
-        self.guest_sales.remove(&token_id);
+        if let Some(sale) = self.guest_sales.remove(&token_id) {
+            if sale.used_curve {
+                self.release_curve_slot();
+            }
+        }
+
+        NearEvent::NftRevoke(vec![NftRevokeData {
+            token_id: &token_id,
+            owner_id: &guest.account_id,
+            revoked_account_id: &market_contract,
+        }])
+        .emit();
 
         // make market remove sale
         ext_market::remove_sale(
@@ -184,8 +270,7 @@ impl Contract {
         );
     }
 
-    /// internal helpers for guest admin
-    
+    // internal helpers for guest admin
     fn admin_guest(&mut self, new_mints: u8) -> Guest {
         let signer_id = env::signer_account_pk();
         let mut guest = self.guests.get(&signer_id).expect("Not a guest");
@@ -204,6 +289,7 @@ impl Contract {
         access_key: Base58PublicKey,
         method_names: String
     ) -> Promise {
+        self.assert_not_paused();
         let pk = env::signer_account_pk();
         let guest = self.guests.get(&pk).expect("No guest");
         let balance: Balance = guest.balance.into();
@@ -232,22 +318,24 @@ impl Contract {
             ))
     }
 
-    /// after the account is created we'll delete all the guests activity
+    /// after the account is created we'll delete all the guests activity. The guest's tokens
+    /// already live under `account_id` (nft_mint_guest sets owner_id to the guest's eventual
+    /// real account from the start), so there's no inventory to migrate here — just drop the
+    /// guest record so the graduated key can no longer call the sponsored guest methods.
     pub fn on_account_created(&mut self, account_id: AccountId, public_key: PublicKey) -> bool {
         let creation_succeeded = is_promise_success();
         if creation_succeeded {
+            env::log(format!("Account {} created, removing guest record", account_id).as_bytes());
             self.guests.remove(&public_key);
         }
         creation_succeeded
     }
 
-    /// only owner/backend API should be able to do this to avoid unwanted storage usage in creating new guest records
-
+    // only owner/backend API should be able to do this to avoid unwanted storage usage in creating new guest records
     /// add account_id to guests for get_predecessor and to storage to receive tokens
     pub fn add_guest(&mut self, account_id: AccountId, public_key: Base58PublicKey) {
+        self.require_role(Role::GuestAdmin);
 
-        assert_eq!(env::predecessor_account_id(), self.owner_id, "must be owner_id");
-        
         if self.tokens_per_owner.get(&account_id).is_some() {
             env::panic(b"The account is already registered");
         }
@@ -261,18 +349,22 @@ impl Contract {
     }
 
     pub fn remove_guest(&mut self, public_key: Base58PublicKey) {
-        assert_eq!(env::predecessor_account_id(), self.owner_id, "must be owner_id");
-        let guest = self.guests.get(&public_key.clone().into()).expect("not a guest");
-        // TODO transfer NFTs
-        self.tokens_per_owner.remove(&guest.account_id);
-        self.guests.remove(&public_key.into());
+        self.require_role(Role::GuestAdmin);
+        let public_key: PublicKey = public_key.into();
+        let guest = self.guests.get(&public_key).expect("not a guest");
+        // reclaim the guest's minted tokens into owner_id's custody instead of orphaning them;
+        // seed_guest_migration removes the guest record once the migration drains
+        self.seed_guest_migration(guest.account_id, self.owner_id.clone(), Some(public_key));
     }
 
-    /// view methods
-
+    // view methods
     pub fn get_guest(&self, public_key: Base58PublicKey) -> Guest {
         self.guests.get(&public_key.into()).expect("no guest")
     }
+
+    pub fn get_migration(&self, from: AccountId) -> Option<OngoingOperation> {
+        self.migrations.get(&from)
+    }
 }
 
 /// external calls to marketplac
@@ -294,8 +386,5 @@ fn is_promise_success() -> bool {
         1,
         "Contract expected a result on the callback"
     );
-    match env::promise_result(0) {
-        PromiseResult::Successful(_) => true,
-        _ => false,
-    }
+    matches!(env::promise_result(0), PromiseResult::Successful(_))
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+use crate::*;
+
+/// NEP-177 structured token metadata. Every field is optional so guest mints stay cheap,
+/// but `nft_mint_guest` enforces that at least `title` or `media` is present.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub media: Option<String>,
+    pub media_hash: Option<String>,
+    pub copies: Option<u64>,
+    pub issued_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub starts_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub extra: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+impl TokenMetadata {
+    pub(crate) fn assert_valid(&self) {
+        assert!(
+            self.title.is_some() || self.media.is_some(),
+            "Token metadata must include a title or media"
+        );
+    }
+}
+
+/// NEP-177 contract-level metadata, set once at `new` and exposed via `nft_metadata`.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NFTContractMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon: Option<String>,
+    pub base_uri: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+#[near_bindgen]
+impl Contract {
+    pub fn nft_metadata(&self) -> NFTContractMetadata {
+        self.metadata.get().expect("Contract metadata not set")
+    }
+}
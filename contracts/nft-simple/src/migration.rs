@@ -0,0 +1,208 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde::{Deserialize, Serialize};
+
+use crate::events::{NearEvent, NftTransferData};
+use crate::*;
+
+/// A guest inventory migration that survives across transactions, so a guest holding
+/// up to `GUEST_MINT_LIMIT` (or more, if that limit is ever raised) tokens can be
+/// moved without blowing the gas ceiling of a single call.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OngoingOperation {
+    pub from: AccountId,
+    pub to: AccountId,
+    pub remaining: Vec<TokenId>,
+    /// the guest record to finalize once the migration drains, if any
+    pub guest_public_key: Option<PublicKey>,
+}
+
+/// how many tokens `continue_guest_migration` will move per call unless the caller asks for fewer
+const DEFAULT_MIGRATION_BATCH: u64 = GUEST_MINT_LIMIT as u64;
+
+#[near_bindgen]
+impl Contract {
+    pub(crate) fn seed_guest_migration(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        guest_public_key: Option<PublicKey>,
+    ) {
+        let remaining: Vec<TokenId> = self
+            .tokens_per_owner
+            .get(&from)
+            .map(|tokens| tokens.iter().collect())
+            .unwrap_or_default();
+
+        if remaining.is_empty() {
+            if let Some(public_key) = guest_public_key {
+                self.guests.remove(&public_key);
+            }
+            return;
+        }
+
+        self.migrations.insert(
+            &from.clone(),
+            &OngoingOperation {
+                from,
+                to,
+                remaining,
+                guest_public_key,
+            },
+        );
+    }
+
+    /// Moves up to `limit` tokens (default `GUEST_MINT_LIMIT`) of an ongoing migration
+    /// from `from` to its destination account, one resumable batch at a time.
+    pub fn continue_guest_migration(&mut self, from: AccountId, limit: Option<u64>) {
+        self.require_role(Role::GuestAdmin);
+        let mut operation = self.migrations.get(&from).expect("no ongoing migration for account");
+        let limit = limit.unwrap_or(DEFAULT_MIGRATION_BATCH) as usize;
+
+        let mut moved_token_ids: Vec<TokenId> = Vec::new();
+        for _ in 0..limit {
+            let token_id = match operation.remaining.pop() {
+                Some(token_id) => token_id,
+                None => break,
+            };
+            self.internal_migrate_token(&operation.from, &operation.to, &token_id);
+            moved_token_ids.push(token_id);
+        }
+
+        if !moved_token_ids.is_empty() {
+            NearEvent::NftTransfer(vec![NftTransferData {
+                authorized_id: None,
+                old_owner_id: &operation.from,
+                new_owner_id: &operation.to,
+                token_ids: moved_token_ids.iter().collect(),
+                memo: None,
+            }])
+            .emit();
+        }
+
+        if operation.remaining.is_empty() {
+            if let Some(public_key) = &operation.guest_public_key {
+                self.guests.remove(public_key);
+            }
+            self.migrations.remove(&from);
+        } else {
+            self.migrations.insert(&from, &operation);
+        }
+    }
+
+    fn internal_migrate_token(&mut self, from: &AccountId, to: &AccountId, token_id: &TokenId) {
+        let mut token = self.tokens_by_id.get(token_id).expect("token in migration missing from tokens_by_id");
+        self.internal_remove_token_from_owner(from, token_id);
+        token.owner_id = to.clone();
+        self.tokens_by_id.insert(token_id, &token);
+        self.internal_add_token_to_owner(to, token_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::json_types::ValidAccountId;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{testing_env, MockedBlockchain};
+    use std::convert::TryInto;
+
+    use super::*;
+    use crate::metadata::NFTContractMetadata;
+
+    fn setup(predecessor: ValidAccountId) -> Contract {
+        testing_env!(VMContextBuilder::new().predecessor_account_id(predecessor.clone()).build());
+        Contract::new(
+            predecessor,
+            NFTContractMetadata {
+                spec: "nft-1.0.0".to_string(),
+                name: "test".to_string(),
+                symbol: "TEST".to_string(),
+                icon: None,
+                base_uri: None,
+                reference: None,
+                reference_hash: None,
+            },
+        )
+    }
+
+    fn mint(contract: &mut Contract, owner: &AccountId, token_id: &str) {
+        contract.tokens_by_id.insert(
+            &token_id.to_string(),
+            &Token {
+                owner_id: owner.clone(),
+                metadata: TokenMetadata {
+                    title: Some("t".to_string()),
+                    description: None,
+                    media: None,
+                    media_hash: None,
+                    copies: None,
+                    issued_at: None,
+                    expires_at: None,
+                    starts_at: None,
+                    updated_at: None,
+                    extra: None,
+                    reference: None,
+                    reference_hash: None,
+                },
+                approved_account_ids: Default::default(),
+            },
+        );
+        contract.internal_add_token_to_owner(owner, &token_id.to_string());
+    }
+
+    #[test]
+    fn seed_guest_migration_with_no_tokens_removes_guest_immediately_and_seeds_nothing() {
+        let owner: AccountId = accounts(0).into();
+        let from: AccountId = accounts(1).into();
+        let to: AccountId = accounts(2).into();
+        let mut contract = setup(owner.try_into().unwrap());
+        let public_key = vec![0u8; 32];
+        contract.guests.insert(&public_key, &Guest { account_id: from.clone(), mints: 0, balance: U128(0) });
+
+        contract.seed_guest_migration(from.clone(), to, Some(public_key.clone()));
+
+        assert!(contract.migrations.get(&from).is_none());
+        assert!(contract.guests.get(&public_key).is_none());
+    }
+
+    #[test]
+    fn continue_guest_migration_drains_in_batches_and_finalizes() {
+        let owner: AccountId = accounts(0).into();
+        let from: AccountId = accounts(1).into();
+        let to: AccountId = accounts(2).into();
+        let mut contract = setup(owner.clone().try_into().unwrap());
+        contract.roles.insert(&owner, &{
+            let mut roles = UnorderedSet::new(b"test-roles".to_vec());
+            roles.insert(&Role::GuestAdmin);
+            roles
+        });
+
+        for i in 0..5 {
+            mint(&mut contract, &from, &format!("token-{}", i));
+        }
+        let public_key = vec![1u8; 32];
+        contract.guests.insert(&public_key, &Guest { account_id: from.clone(), mints: 0, balance: U128(0) });
+
+        contract.seed_guest_migration(from.clone(), to.clone(), Some(public_key.clone()));
+        assert_eq!(contract.migrations.get(&from).unwrap().remaining.len(), 5);
+
+        // first batch: caps at the requested limit, leaving the rest for a later call
+        contract.continue_guest_migration(from.clone(), Some(3));
+        let operation = contract.migrations.get(&from).expect("migration should still be ongoing");
+        assert_eq!(operation.remaining.len(), 2);
+        assert_eq!(contract.tokens_per_owner.get(&to).unwrap().len(), 3);
+        assert!(contract.guests.get(&public_key).is_some(), "guest record survives mid-migration");
+
+        // final batch: drains the rest and finalizes, removing both the migration and the guest
+        contract.continue_guest_migration(from.clone(), Some(3));
+        assert!(contract.migrations.get(&from).is_none());
+        assert!(contract.guests.get(&public_key).is_none());
+        assert_eq!(contract.tokens_per_owner.get(&to).unwrap().len(), 5);
+        assert!(contract.tokens_per_owner.get(&from).is_none());
+        for i in 0..5 {
+            let token = contract.tokens_by_id.get(&format!("token-{}", i)).unwrap();
+            assert_eq!(&token.owner_id, &to);
+        }
+    }
+}
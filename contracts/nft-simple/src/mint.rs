@@ -0,0 +1,37 @@
+use near_sdk::json_types::ValidAccountId;
+use near_sdk::{env, near_bindgen};
+
+use crate::events::{NearEvent, NftMintData};
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    /// paying mint, open to anyone willing to cover the storage they use (contrast with
+    /// `nft_mint_guest`, which is sponsored and therefore capped and rate-limited)
+    #[payable]
+    pub fn nft_mint(&mut self, token_id: TokenId, metadata: TokenMetadata, owner_id: ValidAccountId) {
+        metadata.assert_valid();
+        let initial_storage_usage = env::storage_usage();
+
+        let owner_id: AccountId = owner_id.into();
+        let token = Token {
+            owner_id: owner_id.clone(),
+            metadata,
+            approved_account_ids: Default::default(),
+        };
+        assert!(
+            self.tokens_by_id.insert(&token_id, &token).is_none(),
+            "Token already exists"
+        );
+        self.internal_add_token_to_owner(&owner_id, &token_id);
+        self.total_supply += 1;
+
+        refund_deposit(env::storage_usage() - initial_storage_usage);
+
+        NearEvent::NftMint(vec![NftMintData {
+            owner_id: &owner_id,
+            token_ids: vec![&token_id],
+        }])
+        .emit();
+    }
+}
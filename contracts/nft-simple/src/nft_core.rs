@@ -0,0 +1,105 @@
+use near_sdk::json_types::ValidAccountId;
+use near_sdk::{env, near_bindgen};
+
+use crate::events::{NearEvent, NftApproveData, NftRevokeData, NftTransferData};
+use crate::*;
+
+pub trait NonFungibleTokenCore {
+    fn nft_transfer(&mut self, receiver_id: ValidAccountId, token_id: TokenId, memo: Option<String>);
+    fn nft_token(&self, token_id: TokenId) -> Option<Token>;
+}
+
+pub trait NonFungibleTokenApproval {
+    fn nft_approve(&mut self, token_id: TokenId, account_id: ValidAccountId);
+    fn nft_revoke(&mut self, token_id: TokenId, account_id: ValidAccountId);
+    fn nft_revoke_all(&mut self, token_id: TokenId);
+    fn nft_is_approved(&self, token_id: TokenId, approved_account_id: ValidAccountId) -> bool;
+}
+
+#[near_bindgen]
+impl NonFungibleTokenCore for Contract {
+    #[payable]
+    fn nft_transfer(&mut self, receiver_id: ValidAccountId, token_id: TokenId, memo: Option<String>) {
+        assert_one_yocto();
+        let sender_id = env::predecessor_account_id();
+        let receiver_id: AccountId = receiver_id.into();
+        let mut token = self.tokens_by_id.get(&token_id).expect("Token not found");
+
+        assert!(
+            sender_id == token.owner_id || token.approved_account_ids.contains(&sender_id),
+            "Sender must be the token owner or an approved account"
+        );
+        assert_ne!(token.owner_id, receiver_id, "Receiver must differ from current owner");
+
+        self.internal_remove_token_from_owner(&token.owner_id, &token_id);
+        let old_owner_id = token.owner_id.clone();
+        token.owner_id = receiver_id.clone();
+        token.approved_account_ids.clear();
+        self.tokens_by_id.insert(&token_id, &token);
+        self.internal_add_token_to_owner(&receiver_id, &token_id);
+
+        NearEvent::NftTransfer(vec![NftTransferData {
+            authorized_id: if sender_id == old_owner_id { None } else { Some(&sender_id) },
+            old_owner_id: &old_owner_id,
+            new_owner_id: &receiver_id,
+            token_ids: vec![&token_id],
+            memo: memo.as_deref(),
+        }])
+        .emit();
+    }
+
+    fn nft_token(&self, token_id: TokenId) -> Option<Token> {
+        self.tokens_by_id.get(&token_id)
+    }
+}
+
+#[near_bindgen]
+impl NonFungibleTokenApproval for Contract {
+    #[payable]
+    fn nft_approve(&mut self, token_id: TokenId, account_id: ValidAccountId) {
+        assert_one_yocto();
+        let mut token = self.tokens_by_id.get(&token_id).expect("Token not found");
+        assert_eq!(env::predecessor_account_id(), token.owner_id, "Only the owner can approve");
+        let account_id: AccountId = account_id.into();
+        token.approved_account_ids.insert(account_id.clone());
+        self.tokens_by_id.insert(&token_id, &token);
+
+        NearEvent::NftApprove(vec![NftApproveData {
+            token_id: &token_id,
+            owner_id: &token.owner_id,
+            approved_account_id: &account_id,
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn nft_revoke(&mut self, token_id: TokenId, account_id: ValidAccountId) {
+        assert_one_yocto();
+        let mut token = self.tokens_by_id.get(&token_id).expect("Token not found");
+        assert_eq!(env::predecessor_account_id(), token.owner_id, "Only the owner can revoke");
+        let account_id: AccountId = account_id.into();
+        token.approved_account_ids.remove(&account_id);
+        self.tokens_by_id.insert(&token_id, &token);
+
+        NearEvent::NftRevoke(vec![NftRevokeData {
+            token_id: &token_id,
+            owner_id: &token.owner_id,
+            revoked_account_id: &account_id,
+        }])
+        .emit();
+    }
+
+    #[payable]
+    fn nft_revoke_all(&mut self, token_id: TokenId) {
+        assert_one_yocto();
+        let mut token = self.tokens_by_id.get(&token_id).expect("Token not found");
+        assert_eq!(env::predecessor_account_id(), token.owner_id, "Only the owner can revoke");
+        token.approved_account_ids.clear();
+        self.tokens_by_id.insert(&token_id, &token);
+    }
+
+    fn nft_is_approved(&self, token_id: TokenId, approved_account_id: ValidAccountId) -> bool {
+        let token = self.tokens_by_id.get(&token_id).expect("Token not found");
+        token.approved_account_ids.contains(approved_account_id.as_ref())
+    }
+}
@@ -0,0 +1,107 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::{env, near_bindgen};
+
+use crate::*;
+
+/// Linear bonding curve: `price = initial_price + (slope_numerator * active_listings) /
+/// slope_denominator`, in yoctoNEAR. `active_listings` counts curve listings that were not
+/// withdrawn with `nft_remove_sale_guest`, sold ones included — there's no callback from the
+/// market contract on an actual sale (see `Contract::active_curve_listings`). The
+/// fractional slope is kept as a numerator/denominator pair (rather than a single integer) so
+/// creators can express sub-yocto growth rates without the curve going flat.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BondingCurve {
+    pub initial_price: U128,
+    pub slope_numerator: U128,
+    pub slope_denominator: U128,
+}
+
+impl BondingCurve {
+    /// multiplies before dividing to preserve precision, and saturates rather than overflowing
+    /// `Balance` if a runaway `active_listings` count is ever reached
+    pub(crate) fn price_at(&self, active_listings: u64) -> Balance {
+        let initial_price: Balance = self.initial_price.into();
+        let slope_numerator: Balance = self.slope_numerator.into();
+        let slope_denominator: Balance = self.slope_denominator.into();
+
+        let increment = (slope_numerator.saturating_mul(active_listings as Balance))
+            .checked_div(slope_denominator)
+            .unwrap_or(0);
+
+        initial_price.saturating_add(increment)
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    pub fn set_bonding_curve(&mut self, initial_price: U128, slope_numerator: U128, slope_denominator: U128) {
+        assert_eq!(env::predecessor_account_id(), self.owner_id, "must be owner_id");
+        assert!(slope_denominator.0 > 0, "slope_denominator must be non-zero");
+        self.bonding_curve = Some(BondingCurve {
+            initial_price,
+            slope_numerator,
+            slope_denominator,
+        });
+    }
+
+    pub fn get_bonding_curve(&self) -> Option<BondingCurve> {
+        self.bonding_curve.clone()
+    }
+
+    /// Quotes the next curve price and reserves a slot in `active_curve_listings` for it. The
+    /// reservation is provisional: `nft_remove_sale_guest` gives it back via
+    /// `release_curve_slot` if the listing is pulled before it actually sells, so delisting and
+    /// relisting the same token doesn't permanently ratchet the price up.
+    pub(crate) fn curve_price(&mut self) -> Balance {
+        let curve = self.bonding_curve.as_ref().expect("No bonding curve configured");
+        let price = curve.price_at(self.active_curve_listings);
+        self.active_curve_listings += 1;
+        price
+    }
+
+    pub(crate) fn release_curve_slot(&mut self) {
+        self.active_curve_listings = self.active_curve_listings.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(initial: u128, slope_numerator: u128, slope_denominator: u128) -> BondingCurve {
+        BondingCurve {
+            initial_price: U128(initial),
+            slope_numerator: U128(slope_numerator),
+            slope_denominator: U128(slope_denominator),
+        }
+    }
+
+    #[test]
+    fn price_at_zero_listings_is_initial_price() {
+        let curve = curve(1_000, 1, 1);
+        assert_eq!(curve.price_at(0), 1_000);
+    }
+
+    #[test]
+    fn price_at_grows_linearly_with_active_listings() {
+        let curve = curve(1_000, 100, 1);
+        assert_eq!(curve.price_at(1), 1_100);
+        assert_eq!(curve.price_at(10), 2_000);
+    }
+
+    #[test]
+    fn price_at_applies_fractional_slope() {
+        // slope of 1/2 yoctoNEAR per listing: 3 active listings should add 1 (integer division
+        // truncates 1.5)
+        let curve = curve(1_000, 1, 2);
+        assert_eq!(curve.price_at(3), 1_001);
+    }
+
+    #[test]
+    fn price_at_saturates_instead_of_overflowing() {
+        let curve = curve(Balance::MAX, 1, 1);
+        assert_eq!(curve.price_at(1), Balance::MAX);
+    }
+}
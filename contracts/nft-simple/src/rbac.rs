@@ -0,0 +1,78 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedSet;
+use near_sdk::json_types::ValidAccountId;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near_bindgen};
+
+use crate::*;
+
+/// Distinct from `internal::unique_prefix` (used by `tokens_per_owner`) so a per-account roles
+/// set never shares a storage prefix with that account's owned-token set.
+fn unique_role_prefix(account_id: &AccountId) -> Vec<u8> {
+    let mut prefix = Vec::with_capacity(33);
+    prefix.push(b'R');
+    prefix.extend(env::sha256(account_id.as_bytes()));
+    prefix
+}
+
+/// Roles that can be granted to accounts independently of `owner_id`, so the
+/// owner can delegate (and revoke) specific powers without handing out full control.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    Owner,
+    GuestAdmin,
+    Pauser,
+}
+
+/// owner-only role management
+#[near_bindgen]
+impl Contract {
+    pub fn grant_role(&mut self, account_id: ValidAccountId, role: Role) {
+        assert_eq!(env::predecessor_account_id(), self.owner_id, "must be owner_id");
+        let account_id: AccountId = account_id.into();
+        let mut roles = self.roles.get(&account_id).unwrap_or_else(|| {
+            UnorderedSet::new(unique_role_prefix(&account_id))
+        });
+        roles.insert(&role);
+        self.roles.insert(&account_id, &roles);
+    }
+
+    pub fn revoke_role(&mut self, account_id: ValidAccountId, role: Role) {
+        assert_eq!(env::predecessor_account_id(), self.owner_id, "must be owner_id");
+        let account_id: AccountId = account_id.into();
+        if let Some(mut roles) = self.roles.get(&account_id) {
+            roles.remove(&role);
+            self.roles.insert(&account_id, &roles);
+        }
+    }
+
+    /// incident response: halt sponsored guest activity without redeploying
+    pub fn pause(&mut self) {
+        self.require_role(Role::Pauser);
+        self.paused = true;
+    }
+
+    pub fn unpause(&mut self) {
+        self.require_role(Role::Pauser);
+        self.paused = false;
+    }
+
+    /// panics unless the predecessor holds `role` (owner_id implicitly holds every role)
+    pub(crate) fn require_role(&self, role: Role) {
+        let predecessor = env::predecessor_account_id();
+        if predecessor == self.owner_id {
+            return;
+        }
+        let has_role = self
+            .roles
+            .get(&predecessor)
+            .map(|roles| roles.contains(&role))
+            .unwrap_or(false);
+        assert!(has_role, "predecessor lacks required role");
+    }
+
+    pub(crate) fn assert_not_paused(&self) {
+        assert!(!self.paused, "contract is paused");
+    }
+}
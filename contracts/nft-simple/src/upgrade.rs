@@ -0,0 +1,52 @@
+use near_sdk::{env, near_bindgen, Gas};
+
+use crate::*;
+
+const GAS_FOR_MIGRATE_CALL: Gas = 20_000_000_000_000;
+
+/// Reads new contract code from the call's input and redeploys this account, so a bug in
+/// sponsorship logic or any other structural change doesn't strand funds behind unupgradable code.
+pub trait Upgrade {
+    fn upgrade(&self);
+}
+
+/// Implemented by the freshly deployed code's `Contract`, to adapt the previous binary's
+/// borsh-serialized state into this version's layout (e.g. filling in new fields added by
+/// the events, metadata, RBAC, or bonding-curve work) before normal calls resume.
+pub trait UpgradeHook: Sized {
+    fn migrate() -> Self;
+}
+
+#[near_bindgen]
+impl Upgrade for Contract {
+    fn upgrade(&self) {
+        self.require_role(Role::Owner);
+        let code = env::input().expect("Error: No upgrade code in input");
+
+        // deploy and migrate run in the same batch/receipt on this account, rather than two
+        // separate receipts chained with `.then`, so the redeploy is atomic
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call(b"migrate".to_vec(), vec![], NO_DEPOSIT, GAS_FOR_MIGRATE_CALL);
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Only the self-deploy in `upgrade` should ever call this, so it must run before any
+    /// other state-touching call after a redeploy.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        <Self as UpgradeHook>::migrate()
+    }
+}
+
+impl UpgradeHook for Contract {
+    fn migrate() -> Self {
+        // no field changes land in the same commit that introduces `upgrade` itself; once a
+        // future change adds or reshapes a field, read the old layout into a local struct here
+        // and construct `Self` from it instead of this passthrough.
+        env::state_read().expect("failed to read old contract state")
+    }
+}